@@ -16,9 +16,160 @@ impl AttributeEncoder for FieldElement {
 
     fn from_vec(bytes: Vec<u8>) -> Self::Output {
         let mut data = vec![0u8; amcl_wrapper::constants::FieldElement_SIZE - bytes.len()];
-        data.extend_from_slice(&bytes); 
+        data.extend_from_slice(&bytes);
         FieldElement::from_bytes(data.as_slice()).map_err(|e| format!("{:?}", e)).unwrap()
     }
+
+    fn to_be_bytes(value: &Self::Output) -> Vec<u8> {
+        value.to_bytes()
+    }
+
+    fn encode_from_decimal_string(value: &str) -> Result<Self::Output, String> {
+        use num_bigint::{BigInt, Sign::*};
+
+        let b: bigdecimal::BigDecimal = value.parse().map_err(|e| format!("{:?}", e))?;
+        let (digits, exponent) = b.into_bigint_and_exponent();
+        let (sign, digit_bytes) = digits.to_bytes_be();
+        let digits = BigInt::from_bytes_be(Plus, &digit_bytes);
+
+        // `digits * 10^(-exponent)` is the exact unsigned value; split it
+        // into an integer part added as-is (like `encode_from_isize` adds
+        // its value directly) and a fractional remainder scaled by
+        // `2^BITS_IN_ZERO`, rather than scaling the whole value — the
+        // latter would blow up integer parts past `max()` for anything
+        // beyond a tiny fraction.
+        let (digits, denom) = if exponent < 0 {
+            (digits * BigInt::from(10u8).pow((-exponent) as u32), BigInt::from(1u8))
+        } else {
+            (digits, BigInt::from(10u8).pow(exponent as u32))
+        };
+        let int_part = &digits / &denom;
+        let remainder = &digits % &denom;
+        let frac_scaled = (remainder * (BigInt::from(1u8) << BITS_IN_ZERO)) / denom;
+
+        let (_, bytes) = (int_part + frac_scaled).to_bytes_be();
+
+        if bytes.len() > amcl_wrapper::constants::FieldElement_SIZE {
+            return Err(format!("decimal value '{}' out of range: exceeds maximum encodable value", value));
+        }
+
+        let mut raw = vec![0u8; amcl_wrapper::constants::FieldElement_SIZE - bytes.len()];
+        raw.extend_from_slice(&bytes);
+        let f = Self::from_vec(bytes);
+        match sign {
+            NoSign => Ok(Self::zero_center()),
+            Plus => {
+                if raw > max_magnitude().to_bytes() {
+                    return Err(format!("decimal value '{}' out of range: exceeds maximum encodable value", value));
+                }
+                Ok(Self::zero_center() + f)
+            }
+            Minus => {
+                if raw > Self::zero_center().to_bytes() {
+                    return Err(format!("decimal value '{}' out of range: would underflow below zero", value));
+                }
+                Ok(Self::zero_center() - f)
+            }
+        }
+    }
+
+    fn encode_from_hex_string(value: &str) -> Result<Self::Output, String> {
+        let (negative, unsigned) = match value.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        let unsigned = unsigned.strip_prefix("0x").or_else(|| unsigned.strip_prefix("0X")).unwrap_or(unsigned);
+        let bytes = decode_hex_bytes(unsigned)?;
+
+        if bytes.len() > amcl_wrapper::constants::FieldElement_SIZE {
+            return Err(format!("hex value '{}' out of range: exceeds maximum encodable value", value));
+        }
+
+        let mut raw = vec![0u8; amcl_wrapper::constants::FieldElement_SIZE - bytes.len()];
+        raw.extend_from_slice(&bytes);
+        let f = Self::from_vec(bytes);
+        if negative {
+            if raw > Self::zero_center().to_bytes() {
+                return Err(format!("hex value '{}' out of range: would underflow below zero", value));
+            }
+            Ok(Self::zero_center() - f)
+        } else {
+            if raw > max_magnitude().to_bytes() {
+                return Err(format!("hex value '{}' out of range: exceeds maximum encodable value", value));
+            }
+            Ok(Self::zero_center() + f)
+        }
+    }
+
+    fn encode_from_i128(value: i128) -> Result<Self::Output, String> {
+        let negative = value < 0;
+        let f = Self::from_vec(value.unsigned_abs().to_be_bytes().to_vec());
+        Ok(if negative { Self::zero_center() - f } else { Self::zero_center() + f })
+    }
+
+    fn encode_from_u128(value: u128) -> Result<Self::Output, String> {
+        let f = Self::from_vec(value.to_be_bytes().to_vec());
+        Ok(Self::zero_center() + f)
+    }
+
+    fn encode_from_i128_constant_time(value: i128) -> Result<Self::Output, String> {
+        let magnitude = Self::from_vec(value.unsigned_abs().to_be_bytes().to_vec());
+
+        // `FieldElement`'s `Add`/`Sub` already run in constant time, so
+        // both candidates are computed unconditionally and the final value
+        // is chosen with a byte-wise masked conditional move instead of
+        // branching on the secret sign of `value`.
+        let pos_bytes = (Self::zero_center() + magnitude.clone()).to_bytes();
+        let neg_bytes = (Self::zero_center() - magnitude).to_bytes();
+
+        let mask = 0u8.wrapping_sub((value < 0) as u8);
+        let selected: Vec<u8> = pos_bytes
+            .iter()
+            .zip(neg_bytes.iter())
+            .map(|(&p, &n)| (p & !mask) | (n & mask))
+            .collect();
+
+        Ok(Self::from_vec(selected))
+    }
+
+    fn encode_from_be_bytes(bytes: &[u8]) -> Result<Self::Output, String> {
+        if bytes.len() > amcl_wrapper::constants::FieldElement_SIZE {
+            return Err(format!("big-endian magnitude of {} bytes out of range: exceeds the 256-bit ceiling", bytes.len()));
+        }
+        let mut raw = vec![0u8; amcl_wrapper::constants::FieldElement_SIZE - bytes.len()];
+        raw.extend_from_slice(bytes);
+        let magnitude = Self::from_vec(bytes.to_vec());
+        if raw > max_magnitude().to_bytes() {
+            return Err("big-endian magnitude out of range: exceeds maximum encodable value".to_string());
+        }
+        Ok(Self::zero_center() + magnitude)
+    }
+}
+
+/// The greatest magnitude `from_vec` may hold without `zero_center() +
+/// magnitude` overflowing past `max()`. `FieldElement` arithmetic reduces
+/// modulo the curve order, so an overflowing sum silently wraps instead of
+/// growing past it — the overflow must be caught by comparing the raw
+/// magnitude against this bound up front, not by inspecting the (already
+/// wrapped) sum afterwards.
+fn max_magnitude() -> FieldElement {
+    <FieldElement as AttributeEncoder>::max() - FieldElement::zero_center()
+}
+
+/// Decodes a hexadecimal string (without a sign or `0x` prefix) into its
+/// big-endian byte representation, left-padding with a zero nibble if the
+/// number of digits is odd.
+fn decode_hex_bytes(hex: &str) -> Result<Vec<u8>, String> {
+    let padded = if hex.len() % 2 == 1 { format!("0{}", hex) } else { hex.to_string() };
+    let digits: Vec<char> = padded.chars().collect();
+
+    digits
+        .chunks(2)
+        .map(|pair| {
+            let s: String = pair.iter().collect();
+            u8::from_str_radix(&s, 16).map_err(|e| format!("{:?}", e))
+        })
+        .collect()
 }
 
 #[cfg(test)]
@@ -51,32 +202,128 @@ mod tests {
     fn decimal_test() {
         let res1 = FieldElement::encode_from_f64(1.33f32);
         assert!(res1.is_ok());
-        let res2 = FieldElement::encode_from_f64(-1.33f32);
+        let res2 = FieldElement::encode_from_f64(std::f64::MAX);
         assert!(res2.is_ok());
-        assert_eq!(FieldElement::zero_center(), res1.unwrap() + res2.unwrap());
-
-        let res1 = FieldElement::encode_from_f64(std::f64::MAX);
-        assert!(res1.is_ok());
-        let res2 = res1.unwrap();
+        let res2 = res2.unwrap();
         assert_eq!((&res2 - &res2), FieldElement::zero());
+    }
 
-        let res3 = FieldElement::encode_from_f64(std::f64::MIN);
-        assert!(res3.is_ok());
-        assert_eq!(FieldElement::zero_center(), res3.unwrap() + res2);
+    #[test]
+    fn decimal_string_convert() {
+        let res = FieldElement::encode_from_decimal_string("0");
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), FieldElement::zero_center());
 
-        let res1 = FieldElement::encode_from_f64(std::f64::NEG_INFINITY);
-        assert!(res1.is_ok());
-        assert_eq!(FieldElement::from(8), res1.unwrap());
+        let pos = FieldElement::encode_from_decimal_string("19.99").unwrap();
+        let neg = FieldElement::encode_from_decimal_string("-19.99").unwrap();
+        assert_eq!(FieldElement::zero_center() + FieldElement::zero_center(), pos + neg);
 
-        let co: amcl_wrapper::types::BigNum = *amcl_wrapper::constants::CurveOrder;
-        let pos_inf = FieldElement::from(co) - FieldElement::from(9);
-        let res1 = FieldElement::encode_from_f64(std::f64::INFINITY);
-        assert!(res1.is_ok());
-        assert_eq!(pos_inf, res1.unwrap());
+        let res = FieldElement::encode_from_decimal_string("not-a-number");
+        assert!(res.is_err());
 
-        let res1 = FieldElement::encode_from_f64(std::f64::NAN);
-        assert!(res1.is_ok());
-        assert_eq!(FieldElement::one(), res1.unwrap());
+        // Fits in FieldElement_SIZE bytes but exceeds the curve order once
+        // zero-centered, so it must be rejected rather than
+        // silently wrapping modulo the curve order.
+        let res = FieldElement::encode_from_decimal_string(&"9".repeat(90));
+        assert!(res.is_err());
+        let res = FieldElement::encode_from_decimal_string(&format!("-{}", "9".repeat(90)));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn hex_string_convert() {
+        let res = FieldElement::encode_from_hex_string("0x0");
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), FieldElement::zero_center());
+
+        let pos = FieldElement::encode_from_hex_string("0x1A").unwrap();
+        assert_eq!(pos, FieldElement::zero_center() + FieldElement::from(26));
+
+        let neg = FieldElement::encode_from_hex_string("-0x1A").unwrap();
+        assert_eq!(neg, FieldElement::zero_center() - FieldElement::from(26));
+
+        let res = FieldElement::encode_from_hex_string("zz");
+        assert!(res.is_err());
+
+        // Fits in FieldElement_SIZE bytes but exceeds the curve order once
+        // zero-centered, so it must be rejected rather than silently
+        // wrapping modulo the curve order.
+        let huge = format!("0x{}", "F".repeat(2 * amcl_wrapper::constants::FieldElement_SIZE));
+        let res = FieldElement::encode_from_hex_string(&huge);
+        assert!(res.is_err());
+        let res = FieldElement::encode_from_hex_string(&format!("-{}", huge));
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn wide_integer_convert() {
+        let res = FieldElement::encode_from_i128(0i128);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), FieldElement::zero_center());
+
+        let res = FieldElement::encode_from_i128(1i128);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), FieldElement::zero_center() + FieldElement::one());
+
+        let res = FieldElement::encode_from_i128(-1i128);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), FieldElement::zero_center() - FieldElement::one());
+
+        let res = FieldElement::encode_from_u128(std::u128::MAX);
+        assert!(res.is_ok());
+    }
+
+    #[test]
+    fn be_bytes_convert() {
+        let res = FieldElement::encode_from_be_bytes(&[]);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), FieldElement::zero_center());
+
+        let res = FieldElement::encode_from_be_bytes(&[0x01, 0x00]);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), FieldElement::zero_center() + FieldElement::from(256));
+
+        let res = FieldElement::encode_from_be_bytes(&[0xFF; 33]);
+        assert!(res.is_err());
+
+        // Fits in FieldElement_SIZE bytes but exceeds the curve order once
+        // zero-centered, so it must be rejected rather than silently
+        // wrapping modulo the curve order.
+        let res = FieldElement::encode_from_be_bytes(&[0xFF; amcl_wrapper::constants::FieldElement_SIZE]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn f64_total_order_monotonic() {
+        // Values in strictly ascending order; the totalOrder transform must
+        // preserve that order across every category, including subnormals
+        // and signed zero.
+        let ordered: Vec<f64> = vec![
+            std::f64::NEG_INFINITY,
+            std::f64::MIN,
+            -1.0e300,
+            -1.33,
+            -std::f64::MIN_POSITIVE,
+            -5e-324,
+            -0.0,
+            0.0,
+            5e-324,
+            std::f64::MIN_POSITIVE,
+            1.33,
+            1.0e300,
+            std::f64::MAX,
+            std::f64::INFINITY,
+            std::f64::NAN,
+        ];
+
+        let encoded: Vec<Vec<u8>> = ordered
+            .iter()
+            .map(|v| FieldElement::encode_from_f64(*v).unwrap().to_bytes())
+            .collect();
+
+        for pair in encoded.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
     }
 
     #[test]
@@ -97,4 +344,54 @@ mod tests {
         assert!(res.is_ok());
         assert_eq!(res.unwrap(), FieldElement::zero_center() + FieldElement::from(std::usize::MAX as u64));
     }
+
+    #[test]
+    fn canonical_bytes_round_trip() {
+        use super::super::AttributeTag;
+
+        let value = FieldElement::encode_from_isize(-42isize).unwrap();
+        let bytes = FieldElement::encode_to_canonical_bytes(AttributeTag::Int, &value);
+        let (tag, decoded) = FieldElement::decode_from_canonical_bytes(&bytes).unwrap();
+        assert_eq!(tag, AttributeTag::Int);
+        assert_eq!(decoded, value);
+
+        // Equal values with the same tag always produce equal canonical bytes.
+        let a = FieldElement::encode_to_canonical_bytes(AttributeTag::Unsigned, &FieldElement::zero_center());
+        let b = FieldElement::encode_to_canonical_bytes(AttributeTag::Unsigned, &FieldElement::zero_center());
+        assert_eq!(a, b);
+
+        let res = FieldElement::decode_from_canonical_bytes(&[AttributeTag::RawBytes as u8]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn constant_time_matches_variable_time() {
+        for value in [0i128, 1, -1, 42, -42, std::i128::MAX, std::i128::MIN] {
+            assert_eq!(
+                FieldElement::encode_from_i128(value).unwrap(),
+                FieldElement::encode_from_i128_constant_time(value).unwrap()
+            );
+        }
+
+        for value in [0isize, 1, -1, 42, -42, std::isize::MAX, std::isize::MIN] {
+            assert_eq!(
+                FieldElement::encode_from_isize(value).unwrap(),
+                FieldElement::encode_from_isize_constant_time(value).unwrap()
+            );
+        }
+
+        for value in [0usize, 1, 42, std::usize::MAX] {
+            assert_eq!(
+                FieldElement::encode_from_usize(value).unwrap(),
+                FieldElement::encode_from_usize_constant_time(value).unwrap()
+            );
+        }
+
+        for value in [0.0f64, -0.0, 1.33, -1.33, std::f64::MAX, std::f64::MIN] {
+            assert_eq!(
+                FieldElement::encode_from_f64(value).unwrap(),
+                FieldElement::encode_from_f64_constant_time(value).unwrap()
+            );
+        }
+    }
 }
\ No newline at end of file
@@ -1,10 +1,50 @@
 use chrono::DateTime;
 
-use std::ops::{Add, Sub};
+use std::{convert::TryFrom, ops::{Add, Sub}};
 
 /// How many bits are used to shift 1 to get to zero centering
 const BITS_IN_ZERO: usize = 254;
 
+/// Identifies which `AttributeEncoder` method produced an encoded integer,
+/// so a [canonical transcript](AttributeEncoder::encode_to_canonical_bytes)
+/// records not just the value but how it was derived, letting a verifier
+/// reconstruct or validate the original attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AttributeTag {
+    /// Produced by `encode_from_isize`/`encode_from_i128`
+    Int = 0,
+    /// Produced by `encode_from_usize`/`encode_from_u128`
+    Unsigned = 1,
+    /// Produced by `encode_from_rfc3339_as_unixtimestamp`
+    Rfc3339Timestamp = 2,
+    /// Produced by `encode_from_rfc3339_as_dayssince1900`
+    DaysSince1900 = 3,
+    /// Produced by `encode_from_f64`
+    FixedPointF64 = 4,
+    /// Produced by `encode_from_decimal_string`
+    DecimalString = 5,
+    /// Produced by `encode_from_be_bytes`/`encode_from_hex_string`
+    RawBytes = 6,
+}
+
+impl TryFrom<u8> for AttributeTag {
+    type Error = String;
+
+    fn try_from(tag: u8) -> Result<Self, Self::Error> {
+        match tag {
+            0 => Ok(AttributeTag::Int),
+            1 => Ok(AttributeTag::Unsigned),
+            2 => Ok(AttributeTag::Rfc3339Timestamp),
+            3 => Ok(AttributeTag::DaysSince1900),
+            4 => Ok(AttributeTag::FixedPointF64),
+            5 => Ok(AttributeTag::DecimalString),
+            6 => Ok(AttributeTag::RawBytes),
+            other => Err(format!("unknown attribute type tag: {}", other)),
+        }
+    }
+}
+
 /// Represents an abstract encoder used for converting types to cryptographic integers
 /// Cryptographic integers are limited to 256 bits
 pub trait AttributeEncoder {
@@ -17,6 +57,9 @@ pub trait AttributeEncoder {
     fn zero_center() -> Self::Output;
     /// Takes a vector of bytes and returns `Self::Output`
     fn from_vec(v: Vec<u8>) -> Self::Output;
+    /// Returns the big-endian, unsigned magnitude bytes of `value`, the
+    /// inverse of [`from_vec`](Self::from_vec)
+    fn to_be_bytes(value: &Self::Output) -> Vec<u8>;
 
     /// Takes an date string that is formatted according to RFC3339
     /// and converts it to a cryptographic integer. 
@@ -36,45 +79,53 @@ pub trait AttributeEncoder {
     }
 
     /// Takes a 64-bit floating point number and converts it into
-    /// a cryptographic integer
+    /// a cryptographic integer using the IEEE 754 §5.10 `totalOrder`
+    /// transform: the raw bit pattern of `value` is turned into a `u64`
+    /// key that sorts identically to `totalOrder` over floats (−∞ < finite
+    /// negatives < −0 < +0 < finite positives < +∞, with NaNs at the
+    /// extremes), and that key is centered on [`zero_center`](Self::zero_center)
+    /// so the numeric center of the float line maps onto it. Unlike a
+    /// per-category mapping, this is globally monotonic across every f64,
+    /// including subnormals and signed zero.
     /// `value`: Any type that can be converted into a f64
     fn encode_from_f64<A: Into<f64>>(v: A) -> Result<Self::Output, String> {
-        use std::num::FpCategory::*;
-        use num_bigint::Sign::*;
-
         let value = v.into();
+        let bits = value.to_bits();
+        let key = if bits & (1u64 << 63) != 0 { !bits } else { bits | (1u64 << 63) };
+        let half = 1u64 << 63;
 
         Ok(
-            match value.classify() {
-                Nan => Self::Output::from(1),
-                Subnormal => Self::Output::from(2),
-                Zero => Self::zero_center(),
-                Infinite => {
-                    if value.is_sign_positive() {
-                        Self::max() - Self::Output::from(9)
-                    } else {
-                        Self::Output::from(8)
-                    }
-                },
-                Normal => {
-                    let mut b = bigdecimal::BigDecimal::from(value);
-
-                    for _ in 0..BITS_IN_ZERO {
-                        b = b.double();
-                    }
-                    let (bi, _) = b.into_bigint_and_exponent();
-                    let (sign, bytes) = bi.to_bytes_be();
-                    let f = Self::from_vec(bytes);
-                    match sign {
-                        NoSign => Self::zero_center(),
-                        Plus => f,
-                        Minus => Self::zero_center() - f
-                    }
-                }
+            if key < half {
+                Self::zero_center() - Self::Output::from(half - key)
+            } else {
+                Self::zero_center() + Self::Output::from(key - half)
             }
         )
     }
 
+    /// Takes an arbitrary-precision decimal string (optionally signed, with
+    /// an optional fractional part, e.g. `"-123.456"`) and converts it into
+    /// a cryptographic integer directly, without round-tripping through
+    /// `f64`. This preserves precision that the `f64` intermediate used by
+    /// [`encode_from_f64`](Self::encode_from_f64) would lose for values with
+    /// more significant digits than an `f64` mantissa can hold (monetary
+    /// amounts, large identifiers, high-precision measurements). The integer
+    /// part is added to `zero_center()` directly, the same way
+    /// `encode_from_isize` adds its value directly; only the fractional
+    /// remainder is scaled, by `2^BITS_IN_ZERO`, and added on top. Returns
+    /// an `Err` if `value` cannot be parsed or if the resulting magnitude
+    /// would overflow `max()`.
+    /// `value`: A decimal string, e.g. `"19.99"` or `"-0.0001"`
+    fn encode_from_decimal_string(value: &str) -> Result<Self::Output, String>;
+
+    /// Takes an arbitrary-precision hexadecimal string (optionally signed,
+    /// with an optional `0x`/`0X` prefix) and converts it into a
+    /// cryptographic integer representing that exact integer value,
+    /// without round-tripping through `f64`. Returns an `Err` if `value`
+    /// cannot be parsed or if the magnitude would overflow `max()`.
+    /// `value`: A hex string, e.g. `"0x1a2b"` or `"-2f"`
+    fn encode_from_hex_string(value: &str) -> Result<Self::Output, String>;
+
     /// Takes a signed number and converts it into
     /// a cryptographic integer
     /// `value`: Any type that can be converted into a isize
@@ -93,6 +144,130 @@ pub trait AttributeEncoder {
     fn encode_from_usize<A: Into<usize>>(value: A) -> Result<Self::Output, String> {
         Ok(Self::zero_center() + Self::Output::from(value.into() as u64))
     }
+
+    /// Takes a signed 128-bit integer and converts it into a cryptographic
+    /// integer. Unlike [`encode_from_isize`](Self::encode_from_isize), this
+    /// is not limited to the 64 bits `Output: From<u64>` provides — the
+    /// magnitude is split into big-endian bytes and routed through
+    /// [`from_vec`](Self::from_vec) instead. Returns an `Err` if the
+    /// magnitude would overflow `max()`.
+    /// `value`: A 128-bit signed integer
+    fn encode_from_i128(value: i128) -> Result<Self::Output, String>;
+
+    /// Takes an unsigned 128-bit integer and converts it into a
+    /// cryptographic integer, following the same big-endian byte path as
+    /// [`encode_from_i128`](Self::encode_from_i128). Returns an `Err` if the
+    /// value would overflow `max()`.
+    /// `value`: A 128-bit unsigned integer
+    fn encode_from_u128(value: u128) -> Result<Self::Output, String>;
+
+    /// Takes a signed 128-bit integer and converts it into a cryptographic
+    /// integer the same way [`encode_from_i128`](Self::encode_from_i128)
+    /// does, but on a constant-time code path: both the positive- and
+    /// negative-zero-centered results are computed unconditionally, and the
+    /// final value is chosen with a branch-free masked conditional move,
+    /// the `subtle`-style `ConditionallySelectable` pattern used for
+    /// constant-time GF(2^255−19) field arithmetic, instead of an
+    /// `if value < 0` branch. Backends additionally run their intermediate
+    /// arithmetic in constant time where the underlying library supports it.
+    /// Use this instead of `encode_from_i128` whenever `value` is secret
+    /// (salary, birthdate, identifiers) and must not leak through timing.
+    /// `value`: A 128-bit signed integer that must not leak via timing
+    fn encode_from_i128_constant_time(value: i128) -> Result<Self::Output, String>;
+
+    /// Takes a signed number and converts it into a cryptographic integer
+    /// the same way [`encode_from_isize`](Self::encode_from_isize) does, but
+    /// routed through [`encode_from_i128_constant_time`](Self::encode_from_i128_constant_time)
+    /// so the sign of `value` never drives a branch. Use this instead of
+    /// `encode_from_isize` whenever `value` is secret and must not leak
+    /// through timing.
+    /// `value`: Any type that can be converted into an isize that must not leak via timing
+    fn encode_from_isize_constant_time<A: Into<isize>>(value: A) -> Result<Self::Output, String> {
+        Self::encode_from_i128_constant_time(value.into() as i128)
+    }
+
+    /// Takes an unsigned number and converts it into a cryptographic integer
+    /// the same way [`encode_from_usize`](Self::encode_from_usize) does, but
+    /// routed through [`encode_from_i128_constant_time`](Self::encode_from_i128_constant_time)
+    /// for the same constant-time treatment as
+    /// [`encode_from_isize_constant_time`](Self::encode_from_isize_constant_time).
+    /// `value`: Any type that can be converted into a usize that must not leak via timing
+    fn encode_from_usize_constant_time<A: Into<usize>>(value: A) -> Result<Self::Output, String> {
+        Self::encode_from_i128_constant_time(value.into() as i128)
+    }
+
+    /// Takes a 64-bit floating point number and converts it into a
+    /// cryptographic integer the same way [`encode_from_f64`](Self::encode_from_f64)
+    /// does — via the IEEE 754 §5.10 `totalOrder` transform — but without the
+    /// sign-bit and magnitude-comparison branches `encode_from_f64` uses to
+    /// build its `totalOrder` key: the key's sign bit is folded into a
+    /// branch-free XOR mask, and the resulting signed offset from
+    /// `zero_center()` is routed through
+    /// [`encode_from_i128_constant_time`](Self::encode_from_i128_constant_time)
+    /// for the same branch-free selection `encode_from_isize_constant_time`
+    /// uses. Use this instead of `encode_from_f64` whenever `value` is secret
+    /// and must not leak through timing.
+    /// `value`: Any type that can be converted into a f64 that must not leak via timing
+    fn encode_from_f64_constant_time<A: Into<f64>>(v: A) -> Result<Self::Output, String> {
+        let value = v.into();
+        let bits = value.to_bits();
+        let sign_mask = 0u64.wrapping_sub(bits >> 63) | (1u64 << 63);
+        let key = bits ^ sign_mask;
+        let half = 1u64 << 63;
+
+        Self::encode_from_i128_constant_time(i128::from(key) - i128::from(half))
+    }
+
+    /// Takes the big-endian bytes of an arbitrary non-negative magnitude
+    /// — up to the 256-bit ceiling `Output` supports — and
+    /// zero-centers it into a cryptographic integer. This is the general
+    /// byte-oriented counterpart to `encode_from_u128`/`encode_from_i128`
+    /// for magnitudes wider than 128 bits. Returns an `Err` if `bytes`
+    /// would overflow `max()`.
+    /// `bytes`: A big-endian, unsigned magnitude
+    fn encode_from_be_bytes(bytes: &[u8]) -> Result<Self::Output, String>;
+
+    /// Serializes `value` into a compact, canonical, self-describing
+    /// transcript: a one-byte [`AttributeTag`] recording how `value` was
+    /// produced, a one-byte length prefix, and that many big-endian
+    /// magnitude bytes with any leading zero bytes stripped. Stripping
+    /// leading zeros guarantees that equal values always produce equal
+    /// bytes, regardless of how a particular `Output` backend pads its
+    /// native byte representation, so issuer and verifier agree byte-for-byte.
+    /// `tag`: What kind of input `value` was encoded from
+    /// `value`: The already-encoded cryptographic integer to serialize
+    fn encode_to_canonical_bytes(tag: AttributeTag, value: &Self::Output) -> Vec<u8> {
+        let raw = Self::to_be_bytes(value);
+        let magnitude = match raw.iter().position(|&b| b != 0) {
+            Some(i) => &raw[i..],
+            None => &[0u8][..],
+        };
+
+        let mut out = Vec::with_capacity(2 + magnitude.len());
+        out.push(tag as u8);
+        out.push(magnitude.len() as u8);
+        out.extend_from_slice(magnitude);
+        out
+    }
+
+    /// Parses a transcript produced by
+    /// [`encode_to_canonical_bytes`](Self::encode_to_canonical_bytes) back
+    /// into the [`AttributeTag`] it was encoded with and the cryptographic
+    /// integer it represents.
+    /// `bytes`: A canonical transcript: tag byte, length byte, magnitude
+    fn decode_from_canonical_bytes(bytes: &[u8]) -> Result<(AttributeTag, Self::Output), String> {
+        let &[tag, len, ref rest @ ..] = bytes else {
+            return Err("canonical bytes too short: missing type tag or length prefix".to_string());
+        };
+        let tag = AttributeTag::try_from(tag)?;
+        let len = len as usize;
+
+        let magnitude = rest.get(..len).ok_or_else(|| {
+            format!("canonical bytes truncated: expected {} magnitude bytes, got {}", len, rest.len())
+        })?;
+
+        Ok((tag, Self::from_vec(magnitude.to_vec())))
+    }
 }
 
 
@@ -1,16 +1,58 @@
 use super::{AttributeEncoder, BITS_IN_ZERO};
 
 use openssl::{
-    bn::{BigNum, BigNumRef}
+    bn::{BigNum, BigNumContext, BigNumRef}
 };
 
-use std::{cmp::{Eq, PartialEq}, ops::{Add, Sub}};
+use std::{
+    cell::RefCell,
+    cmp::{Eq, Ord, Ordering, PartialEq, PartialOrd},
+    ops::{Add, Div, Mul, Neg, Rem, Sub},
+};
+
+thread_local! {
+    /// A `BN_CTX` shared by every `BigNumber` arithmetic operation on this
+    /// thread, rather than allocating a fresh one per call.
+    static BN_CTX: RefCell<BigNumContext> = RefCell::new(BigNumContext::new().expect("failed to allocate BN_CTX"));
+}
+
+/// Renders `bn` as big-endian bytes left-padded with zeros to exactly
+/// `width` bytes, so two `BigNum`s can be compared/selected byte-wise
+/// without their natural (variable) encoded lengths leaking information.
+fn to_fixed_width_be(bn: &BigNum, width: usize) -> Vec<u8> {
+    let bytes = bn.to_vec();
+    let mut out = vec![0u8; width - bytes.len()];
+    out.extend_from_slice(&bytes);
+    out
+}
+
+/// Returns `2^bits` as a `BigNum` — the literal fixed-point scale
+/// `encode_from_decimal_string` applies to a fractional remainder.
+fn pow2(bits: usize) -> BigNum {
+    let mut bn = BigNum::new().unwrap();
+    bn.set_bit(bits as i32).unwrap();
+    bn
+}
 
 /// A simple wrapper class for converting attributes to cryptographic integers
 /// represented in OpenSSL's BigNum library
 #[derive(Debug)]
 pub struct BigNumber(pub BigNum);
 
+impl BigNumber {
+    /// Reduces `self` modulo `modulus`, returning a value in `[0, modulus)`
+    /// so predicate proofs over encoded attributes stay within the signing
+    /// group.
+    pub fn mod_reduce(&self, modulus: &BigNumber) -> BigNumber {
+        BN_CTX.with(|ctx| {
+            let mut ctx = ctx.borrow_mut();
+            let mut bn = BigNum::new().unwrap();
+            BigNumRef::nnmod(&mut bn, &self.0, &modulus.0, &mut ctx).unwrap();
+            BigNumber(bn)
+        })
+    }
+}
+
 impl Add for BigNumber {
     type Output = Self;
 
@@ -51,6 +93,94 @@ impl<'a, 'b> Sub<&'b BigNumber> for &'a BigNumber {
     }
 }
 
+impl Mul for BigNumber {
+    type Output = Self;
+
+    fn mul(self, rhs: Self::Output) -> Self::Output {
+        BN_CTX.with(|ctx| {
+            let mut ctx = ctx.borrow_mut();
+            let mut bn = BigNum::new().unwrap();
+            BigNumRef::checked_mul(&mut bn, &self.0, &rhs.0, &mut ctx).unwrap();
+            BigNumber(bn)
+        })
+    }
+}
+
+impl<'a, 'b> Mul<&'b BigNumber> for &'a BigNumber {
+    type Output = BigNumber;
+
+    fn mul(self, rhs: &'b BigNumber) -> BigNumber {
+        BN_CTX.with(|ctx| {
+            let mut ctx = ctx.borrow_mut();
+            let mut bn = BigNum::new().unwrap();
+            BigNumRef::checked_mul(&mut bn, &self.0, &rhs.0, &mut ctx).unwrap();
+            BigNumber(bn)
+        })
+    }
+}
+
+impl Div for BigNumber {
+    type Output = Self;
+
+    fn div(self, rhs: Self::Output) -> Self::Output {
+        BN_CTX.with(|ctx| {
+            let mut ctx = ctx.borrow_mut();
+            let mut bn = BigNum::new().unwrap();
+            BigNumRef::checked_div(&mut bn, &self.0, &rhs.0, &mut ctx).unwrap();
+            BigNumber(bn)
+        })
+    }
+}
+
+impl<'a, 'b> Div<&'b BigNumber> for &'a BigNumber {
+    type Output = BigNumber;
+
+    fn div(self, rhs: &'b BigNumber) -> BigNumber {
+        BN_CTX.with(|ctx| {
+            let mut ctx = ctx.borrow_mut();
+            let mut bn = BigNum::new().unwrap();
+            BigNumRef::checked_div(&mut bn, &self.0, &rhs.0, &mut ctx).unwrap();
+            BigNumber(bn)
+        })
+    }
+}
+
+impl Rem for BigNumber {
+    type Output = Self;
+
+    fn rem(self, rhs: Self::Output) -> Self::Output {
+        BN_CTX.with(|ctx| {
+            let mut ctx = ctx.borrow_mut();
+            let mut bn = BigNum::new().unwrap();
+            BigNumRef::checked_rem(&mut bn, &self.0, &rhs.0, &mut ctx).unwrap();
+            BigNumber(bn)
+        })
+    }
+}
+
+impl<'a, 'b> Rem<&'b BigNumber> for &'a BigNumber {
+    type Output = BigNumber;
+
+    fn rem(self, rhs: &'b BigNumber) -> BigNumber {
+        BN_CTX.with(|ctx| {
+            let mut ctx = ctx.borrow_mut();
+            let mut bn = BigNum::new().unwrap();
+            BigNumRef::checked_rem(&mut bn, &self.0, &rhs.0, &mut ctx).unwrap();
+            BigNumber(bn)
+        })
+    }
+}
+
+impl Neg for BigNumber {
+    type Output = Self;
+
+    fn neg(mut self) -> Self::Output {
+        let negative = self.0.is_negative();
+        self.0.set_negative(!negative);
+        self
+    }
+}
+
 impl From<u64> for BigNumber {
     fn from(v: u64) -> Self {
         BigNumber(BigNum::from_slice(&v.to_be_bytes()[..]).unwrap())
@@ -65,6 +195,18 @@ impl PartialEq for BigNumber {
 
 impl Eq for BigNumber{}
 
+impl PartialOrd for BigNumber {
+    fn partial_cmp(&self, other: &BigNumber) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigNumber {
+    fn cmp(&self, other: &BigNumber) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
 impl AttributeEncoder for BigNumber {
     type Output = BigNumber;
 
@@ -80,13 +222,162 @@ impl AttributeEncoder for BigNumber {
     }
 
     fn from_vec(bytes: Vec<u8>) -> Self::Output {
-        Self(BigNum::from_slice(bytes.as_slice()).unwrap()) 
+        Self(BigNum::from_slice(bytes.as_slice()).unwrap())
+    }
+
+    fn to_be_bytes(value: &Self::Output) -> Vec<u8> {
+        value.0.to_vec()
+    }
+
+    fn encode_from_decimal_string(value: &str) -> Result<Self::Output, String> {
+        let (negative, unsigned) = match value.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        let (int_part, frac_part) = match unsigned.split_once('.') {
+            Some((i, f)) => (i, f),
+            None => (unsigned, ""),
+        };
+        if int_part.is_empty() && frac_part.is_empty() {
+            return Err(format!("decimal value '{}' is not a valid number: no digits found", value));
+        }
+        let int_value = BigNum::from_dec_str(if int_part.is_empty() { "0" } else { int_part })
+            .map_err(|e| format!("{:?}", e))?;
+
+        // Only the fractional remainder is scaled by `2^BITS_IN_ZERO`,
+        // added on top of the plain integer part (the way
+        // `encode_from_isize` adds its value directly) rather than scaling
+        // the whole digit string, which would blow the integer part past
+        // `max()` for anything beyond a tiny fraction.
+        let magnitude = if frac_part.is_empty() {
+            int_value
+        } else {
+            let numerator = BigNum::from_dec_str(frac_part).map_err(|e| format!("{:?}", e))?;
+            let denom = BigNum::from_dec_str(&format!("1{}", "0".repeat(frac_part.len())))
+                .map_err(|e| format!("{:?}", e))?;
+
+            let mut ctx = BigNumContext::new().map_err(|e| format!("{:?}", e))?;
+            let mut scaled = BigNum::new().map_err(|e| format!("{:?}", e))?;
+            BigNumRef::checked_mul(&mut scaled, &numerator, &pow2(BITS_IN_ZERO), &mut ctx)
+                .map_err(|e| format!("{:?}", e))?;
+
+            let mut frac_scaled = BigNum::new().map_err(|e| format!("{:?}", e))?;
+            BigNumRef::checked_div(&mut frac_scaled, &scaled, &denom, &mut ctx)
+                .map_err(|e| format!("{:?}", e))?;
+
+            let mut magnitude = BigNum::new().map_err(|e| format!("{:?}", e))?;
+            BigNumRef::checked_add(&mut magnitude, &int_value, &frac_scaled)
+                .map_err(|e| format!("{:?}", e))?;
+            magnitude
+        };
+
+        if negative {
+            if magnitude > Self::zero_center().0 {
+                return Err(format!("decimal value '{}' out of range: would underflow below zero", value));
+            }
+            Ok(Self::zero_center() - Self(magnitude))
+        } else {
+            let out = Self::zero_center() + Self(magnitude);
+            if out.0 > <Self as AttributeEncoder>::max().0 {
+                return Err(format!("decimal value '{}' out of range: exceeds maximum encodable value", value));
+            }
+            Ok(out)
+        }
+    }
+
+    fn encode_from_hex_string(value: &str) -> Result<Self::Output, String> {
+        let (negative, unsigned) = match value.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, value.strip_prefix('+').unwrap_or(value)),
+        };
+        let unsigned = unsigned.strip_prefix("0x").or_else(|| unsigned.strip_prefix("0X")).unwrap_or(unsigned);
+        let magnitude = BigNum::from_hex_str(unsigned).map_err(|e| format!("{:?}", e))?;
+
+        if negative {
+            if magnitude > Self::zero_center().0 {
+                return Err(format!("hex value '{}' out of range: would underflow below zero", value));
+            }
+            Ok(Self::zero_center() - Self(magnitude))
+        } else {
+            let out = Self::zero_center() + Self(magnitude);
+            if out.0 > <Self as AttributeEncoder>::max().0 {
+                return Err(format!("hex value '{}' out of range: exceeds maximum encodable value", value));
+            }
+            Ok(out)
+        }
+    }
+
+    fn encode_from_i128(value: i128) -> Result<Self::Output, String> {
+        let negative = value < 0;
+        let magnitude = Self::from_vec(value.unsigned_abs().to_be_bytes().to_vec());
+
+        if negative {
+            if magnitude.0 > Self::zero_center().0 {
+                return Err(format!("i128 value {} out of range: would underflow below zero", value));
+            }
+            Ok(Self::zero_center() - magnitude)
+        } else {
+            let out = Self::zero_center() + magnitude;
+            if out.0 > <Self as AttributeEncoder>::max().0 {
+                return Err(format!("i128 value {} out of range: exceeds maximum encodable value", value));
+            }
+            Ok(out)
+        }
+    }
+
+    fn encode_from_u128(value: u128) -> Result<Self::Output, String> {
+        let magnitude = Self::from_vec(value.to_be_bytes().to_vec());
+        let out = Self::zero_center() + magnitude;
+        if out.0 > <Self as AttributeEncoder>::max().0 {
+            return Err(format!("u128 value {} out of range: exceeds maximum encodable value", value));
+        }
+        Ok(out)
+    }
+
+    fn encode_from_i128_constant_time(value: i128) -> Result<Self::Output, String> {
+        let mut magnitude = BigNum::from_slice(&value.unsigned_abs().to_be_bytes()).unwrap();
+        magnitude.set_const_time();
+
+        let mut center = Self::zero_center().0;
+        center.set_const_time();
+
+        let mut pos = BigNum::new().unwrap();
+        pos.set_const_time();
+        BigNumRef::checked_add(&mut pos, &center, &magnitude).unwrap();
+
+        let mut neg = BigNum::new().unwrap();
+        neg.set_const_time();
+        BigNumRef::checked_sub(&mut neg, &center, &magnitude).unwrap();
+
+        // Both candidates are always computed; the final value is chosen
+        // with a byte-wise masked conditional move instead of branching on
+        // the secret sign of `value`.
+        let mask = 0u8.wrapping_sub((value < 0) as u8);
+        let selected: Vec<u8> = to_fixed_width_be(&pos, 32)
+            .iter()
+            .zip(to_fixed_width_be(&neg, 32).iter())
+            .map(|(&p, &n)| (p & !mask) | (n & mask))
+            .collect();
+
+        Ok(Self(BigNum::from_slice(&selected).unwrap()))
+    }
+
+    fn encode_from_be_bytes(bytes: &[u8]) -> Result<Self::Output, String> {
+        if bytes.len() > 32 {
+            return Err(format!("big-endian magnitude of {} bytes out of range: exceeds the 256-bit ceiling", bytes.len()));
+        }
+        let out = Self::zero_center() + Self::from_vec(bytes.to_vec());
+        if out.0 > <Self as AttributeEncoder>::max().0 {
+            return Err("big-endian magnitude out of range: exceeds maximum encodable value".to_string());
+        }
+        Ok(out)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use super::super::AttributeTag;
 
     #[test]
     fn rfc3339_string_convert() {
@@ -112,29 +403,194 @@ mod tests {
         assert!(res1.is_ok());
         let res2 = BigNumber::encode_from_f64(-1.33f32);
         assert!(res2.is_ok());
-        assert_eq!(BigNumber::zero_center(), res1.unwrap() + res2.unwrap());
+        assert!(res2.unwrap().0 < res1.unwrap().0);
 
         let res1 = BigNumber::encode_from_f64(std::f64::MAX);
         assert!(res1.is_ok());
         let res2 = res1.unwrap();
         assert_eq!((&res2 - &res2).0, BigNum::new().unwrap());
+    }
+
+    #[test]
+    fn decimal_string_convert() {
+        let res = BigNumber::encode_from_decimal_string("0");
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), BigNumber::zero_center());
 
-        let res3 = BigNumber::encode_from_f64(std::f64::MIN);
-        assert!(res3.is_ok());
-        assert_eq!(BigNumber::zero_center(), &res3.unwrap() + &res2);
+        let pos = BigNumber::encode_from_decimal_string("19.99").unwrap();
+        let neg = BigNumber::encode_from_decimal_string("-19.99").unwrap();
+        assert!(neg.0 < BigNumber::zero_center().0);
+        assert!(pos.0 > BigNumber::zero_center().0);
+        assert!(neg.0 < pos.0);
 
-        let res1 = BigNumber::encode_from_f64(std::f64::NEG_INFINITY);
-        assert!(res1.is_ok());
-        assert_eq!(BigNum::from_u32(8).unwrap(), res1.unwrap().0);
+        let res = BigNumber::encode_from_decimal_string("not-a-number");
+        assert!(res.is_err());
 
-        let pos_inf = BigNumber::max() - BigNumber::from(9);
-        let res1 = BigNumber::encode_from_f64(std::f64::INFINITY);
-        assert!(res1.is_ok());
-        assert_eq!(pos_inf, res1.unwrap());
+        // A bare sign or decimal point with no digits is not a number.
+        assert!(BigNumber::encode_from_decimal_string("-").is_err());
+        assert!(BigNumber::encode_from_decimal_string("+").is_err());
+        assert!(BigNumber::encode_from_decimal_string(".").is_err());
+        assert!(BigNumber::encode_from_decimal_string("").is_err());
+    }
 
-        let res1 = BigNumber::encode_from_f64(std::f64::NAN);
-        assert!(res1.is_ok());
-        assert_eq!(BigNum::from_u32(1).unwrap(), res1.unwrap().0);
+    #[test]
+    fn hex_string_convert() {
+        let res = BigNumber::encode_from_hex_string("0x0");
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), BigNumber::zero_center());
+
+        let pos = BigNumber::encode_from_hex_string("0x1A").unwrap();
+        assert_eq!(pos, BigNumber::zero_center() + BigNumber::from(26));
+
+        let neg = BigNumber::encode_from_hex_string("-0x1A").unwrap();
+        assert_eq!(neg, BigNumber::zero_center() - BigNumber::from(26));
+
+        let res = BigNumber::encode_from_hex_string("zz");
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn wide_integer_convert() {
+        let res = BigNumber::encode_from_i128(0i128);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), BigNumber::zero_center());
+
+        let res = BigNumber::encode_from_i128(std::i128::MAX);
+        assert!(res.is_ok());
+        assert!(res.unwrap().0 > BigNumber::zero_center().0);
+
+        let res = BigNumber::encode_from_i128(std::i128::MIN);
+        assert!(res.is_ok());
+        assert!(res.unwrap().0 < BigNumber::zero_center().0);
+
+        let res = BigNumber::encode_from_u128(std::u128::MAX);
+        assert!(res.is_ok());
+        assert!(res.unwrap().0 > BigNumber::zero_center().0);
+    }
+
+    #[test]
+    fn be_bytes_convert() {
+        let res = BigNumber::encode_from_be_bytes(&[]);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), BigNumber::zero_center());
+
+        let res = BigNumber::encode_from_be_bytes(&[0x01, 0x00]);
+        assert!(res.is_ok());
+        assert_eq!(res.unwrap(), BigNumber::zero_center() + BigNumber::from(256));
+
+        let res = BigNumber::encode_from_be_bytes(&[0xFF; 33]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        let six = BigNumber::from(6);
+        let seven = BigNumber::from(7);
+        let forty_two = BigNumber::from(42);
+
+        assert_eq!(&six * &seven, forty_two);
+        assert_eq!(BigNumber::from(42) / BigNumber::from(6), BigNumber::from(7));
+        assert_eq!(BigNumber::from(43) % BigNumber::from(6), BigNumber::from(1));
+        assert_eq!(-BigNumber::from(6), BigNumber(BigNum::from_dec_str("-6").unwrap()));
+    }
+
+    #[test]
+    fn total_ordering() {
+        assert!(BigNumber::from(1) < BigNumber::from(2));
+        assert!(BigNumber::from(2) > BigNumber::from(1));
+        assert_eq!(BigNumber::from(1).cmp(&BigNumber::from(1)), std::cmp::Ordering::Equal);
+
+        let mut values = vec![BigNumber::from(3), BigNumber::from(1), BigNumber::from(2)];
+        values.sort();
+        assert_eq!(values, vec![BigNumber::from(1), BigNumber::from(2), BigNumber::from(3)]);
+    }
+
+    #[test]
+    fn mod_reduce_stays_in_group() {
+        let modulus = BigNumber::from(5);
+        assert_eq!(BigNumber::from(12).mod_reduce(&modulus), BigNumber::from(2));
+        assert_eq!((-BigNumber::from(2)).mod_reduce(&modulus), BigNumber::from(3));
+    }
+
+    #[test]
+    fn canonical_bytes_round_trip() {
+        let value = BigNumber::encode_from_isize(-42isize).unwrap();
+        let bytes = BigNumber::encode_to_canonical_bytes(AttributeTag::Int, &value);
+        let (tag, decoded) = BigNumber::decode_from_canonical_bytes(&bytes).unwrap();
+        assert_eq!(tag, AttributeTag::Int);
+        assert_eq!(decoded, value);
+
+        // Equal values with the same tag always produce equal canonical bytes.
+        let a = BigNumber::encode_to_canonical_bytes(AttributeTag::Unsigned, &BigNumber::zero_center());
+        let b = BigNumber::encode_to_canonical_bytes(AttributeTag::Unsigned, &BigNumber::zero_center());
+        assert_eq!(a, b);
+
+        let res = BigNumber::decode_from_canonical_bytes(&[AttributeTag::RawBytes as u8]);
+        assert!(res.is_err());
+    }
+
+    #[test]
+    fn constant_time_matches_variable_time() {
+        for value in [0i128, 1, -1, 42, -42, std::i128::MAX, std::i128::MIN] {
+            assert_eq!(
+                BigNumber::encode_from_i128(value).unwrap(),
+                BigNumber::encode_from_i128_constant_time(value).unwrap()
+            );
+        }
+
+        for value in [0isize, 1, -1, 42, -42, std::isize::MAX, std::isize::MIN] {
+            assert_eq!(
+                BigNumber::encode_from_isize(value).unwrap(),
+                BigNumber::encode_from_isize_constant_time(value).unwrap()
+            );
+        }
+
+        for value in [0usize, 1, 42, std::usize::MAX] {
+            assert_eq!(
+                BigNumber::encode_from_usize(value).unwrap(),
+                BigNumber::encode_from_usize_constant_time(value).unwrap()
+            );
+        }
+
+        for value in [0.0f64, -0.0, 1.33, -1.33, std::f64::MAX, std::f64::MIN] {
+            assert_eq!(
+                BigNumber::encode_from_f64(value).unwrap(),
+                BigNumber::encode_from_f64_constant_time(value).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn f64_total_order_monotonic() {
+        // Values in strictly ascending order; the totalOrder transform must
+        // preserve that order across every category, including subnormals
+        // and signed zero.
+        let ordered: Vec<f64> = vec![
+            std::f64::NEG_INFINITY,
+            std::f64::MIN,
+            -1.0e300,
+            -1.33,
+            -std::f64::MIN_POSITIVE,
+            -5e-324,
+            -0.0,
+            0.0,
+            5e-324,
+            std::f64::MIN_POSITIVE,
+            1.33,
+            1.0e300,
+            std::f64::MAX,
+            std::f64::INFINITY,
+            std::f64::NAN,
+        ];
+
+        let encoded: Vec<BigNum> = ordered
+            .iter()
+            .map(|v| BigNumber::encode_from_f64(*v).unwrap().0)
+            .collect();
+
+        for pair in encoded.windows(2) {
+            assert!(pair[0] < pair[1]);
+        }
     }
 
     #[test]